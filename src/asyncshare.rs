@@ -0,0 +1,155 @@
+use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+// This module is only compiled when the `async` feature is enabled (see the
+// `#[cfg(feature = "async")]` on its `mod` declaration in lib.rs).
+/// Async shared state wrapper for use inside async tasks.
+///
+/// Backed by `tokio::sync::RwLock`, whose internal semaphore wakes queued
+/// waiters in first-in-first-out order, so a stream of readers can't starve
+/// a waiting writer. Guards are `Send` so they can be held across `.await`
+/// points.
+pub struct AsyncShare<T> {
+    inner: RwLock<T>,
+}
+
+impl<T> AsyncShare<T> {
+    /// Creates a new 'AsyncShare' wrapping the given value.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::asyncshare::AsyncShare;
+    /// let shared = AsyncShare::new(42);
+    /// ```
+    ///
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: RwLock::new(value),
+        }
+    }
+
+    /// Provides immutable access via a scoped async closure.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::asyncshare::AsyncShare;
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let shared = AsyncShare::new(42);
+    /// shared.with(|val| print!("{val}")).await;
+    /// # });
+    /// ```
+    ///
+    pub async fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        let guard = self.inner.read().await;
+        f(&guard)
+    }
+
+    /// Provides mutable access via a scoped async closure.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::asyncshare::AsyncShare;
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let shared = AsyncShare::new(42);
+    /// shared.with_mut(|val| *val += 1).await;
+    /// # });
+    /// ```
+    ///
+    pub async fn with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let mut guard = self.inner.write().await;
+        f(&mut guard)
+    }
+
+    /// Attempts to provide immutable access without waiting. Returns `None`
+    /// if the lock is currently unavailable.
+    pub fn try_with<R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        self.inner.try_read().ok().map(|guard| f(&guard))
+    }
+
+    /// Attempts to provide mutable access without waiting. Returns `None`
+    /// if the lock is currently unavailable.
+    pub fn try_with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        self.inner.try_write().ok().map(|mut guard| f(&mut guard))
+    }
+
+    /// Aquires an immutable borrow guard, queueing behind any waiters ahead
+    /// of it in FIFO order.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::asyncshare::AsyncShare;
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let shared = AsyncShare::new(42);
+    /// let guard = shared.read().await;
+    /// println!("Value: {}", *guard);
+    /// # });
+    /// ```
+    ///
+    pub async fn read(&self) -> RwLockReadGuard<'_, T> {
+        self.inner.read().await
+    }
+
+    /// Aquires a mutable borrow guard, queueing behind any waiters ahead of
+    /// it in FIFO order.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::asyncshare::AsyncShare;
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let shared = AsyncShare::new(42);
+    /// let mut guard = shared.write().await;
+    /// *guard += 1;
+    /// # });
+    /// ```
+    ///
+    pub async fn write(&self) -> RwLockWriteGuard<'_, T> {
+        self.inner.write().await
+    }
+
+    /// Clones and returns the inner value. Requires `T: Clone`.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::asyncshare::AsyncShare;
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let shared = AsyncShare::new(42);
+    /// let snapshot = shared.snapshot().await;
+    /// # });
+    /// ```
+    ///
+    pub async fn snapshot(&self) -> T
+    where
+        T: Clone,
+    {
+        self.with(|val| val.clone()).await
+    }
+
+    /// Replaces the inner value with a new one.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::asyncshare::AsyncShare;
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let shared = AsyncShare::new(42);
+    /// shared.replace(100).await;
+    /// # });
+    /// ```
+    ///
+    pub async fn replace(&self, new: T) {
+        self.with_mut(|val| *val = new).await;
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for AsyncShare<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.inner.try_read() {
+            Ok(guard) => f
+                .debug_struct("AsyncShare")
+                .field("value", &*guard)
+                .finish(),
+            Err(_) => f
+                .debug_struct("AsyncShare")
+                .field("value", &"<locked>")
+                .finish(),
+        }
+    }
+}
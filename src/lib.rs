@@ -0,0 +1,6 @@
+pub mod scopeshare;
+pub mod share;
+pub mod syncshare;
+
+#[cfg(feature = "async")]
+pub mod asyncshare;
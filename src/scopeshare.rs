@@ -135,6 +135,45 @@ pub struct ScopeRef<'a, T> {
     inner: Ref<'a, T>,
 }
 
+impl<'a, T> ScopeRef<'a, T> {
+    /// Projects this guard onto a field (or any derived reference) of `T`,
+    /// returning a new guard that keeps the original borrow alive.
+    ///
+    /// # Example
+    /// '''
+    /// let guard = shared.borrow();
+    /// let field = ScopeRef::map(guard, |v| &v.0);
+    /// '''
+    ///
+    pub fn map<U, F>(orig: Self, f: F) -> MappedScopeRef<'a, U>
+    where
+        F: FnOnce(&T) -> &U,
+    {
+        MappedScopeRef {
+            inner: Ref::map(orig.inner, f),
+        }
+    }
+
+    /// Attempts to project this guard onto a derived reference of `T`,
+    /// returning the original guard back if `f` fails.
+    ///
+    /// # Example
+    /// '''
+    /// let guard = shared.borrow();
+    /// let field = ScopeRef::try_map(guard, |v| v.get(0));
+    /// '''
+    ///
+    pub fn try_map<U, F>(orig: Self, f: F) -> Result<MappedScopeRef<'a, U>, Self>
+    where
+        F: FnOnce(&T) -> Option<&U>,
+    {
+        match Ref::filter_map(orig.inner, f) {
+            Ok(inner) => Ok(MappedScopeRef { inner }),
+            Err(inner) => Err(ScopeRef { inner }),
+        }
+    }
+}
+
 impl<'a, T> std::ops::Deref for ScopeRef<'a, T> {
     type Target = T;
 
@@ -153,6 +192,45 @@ pub struct ScopeRefMut<'a, T> {
     inner: RefMut<'a, T>,
 }
 
+impl<'a, T> ScopeRefMut<'a, T> {
+    /// Projects this guard onto a field (or any derived mutable reference) of
+    /// `T`, returning a new guard that keeps the original borrow alive.
+    ///
+    /// # Example
+    /// '''
+    /// let guard = shared.borrow_mut();
+    /// let field = ScopeRefMut::map(guard, |v| &mut v.0);
+    /// '''
+    ///
+    pub fn map<U, F>(orig: Self, f: F) -> MappedScopeRefMut<'a, U>
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        MappedScopeRefMut {
+            inner: RefMut::map(orig.inner, f),
+        }
+    }
+
+    /// Attempts to project this guard onto a derived mutable reference of
+    /// `T`, returning the original guard back if `f` fails.
+    ///
+    /// # Example
+    /// '''
+    /// let guard = shared.borrow_mut();
+    /// let field = ScopeRefMut::try_map(guard, |v| v.get_mut(0));
+    /// '''
+    ///
+    pub fn try_map<U, F>(orig: Self, f: F) -> Result<MappedScopeRefMut<'a, U>, Self>
+    where
+        F: FnOnce(&mut T) -> Option<&mut U>,
+    {
+        match RefMut::filter_map(orig.inner, f) {
+            Ok(inner) => Ok(MappedScopeRefMut { inner }),
+            Err(inner) => Err(ScopeRefMut { inner }),
+        }
+    }
+}
+
 impl<'a, T> std::ops::Deref for ScopeRefMut<'a, T> {
     type Target = T;
     fn deref(&self) -> &Self::Target {
@@ -171,3 +249,48 @@ impl<'a, T: fmt::Debug> fmt::Debug for ScopeRefMut<'a, T> {
         fmt::Debug::fmt(&self.inner, f)
     }
 }
+
+/// A guard produced by [`ScopeRef::map`] or [`ScopeRef::try_map`], borrowing
+/// a projection of the originally borrowed value.
+pub struct MappedScopeRef<'a, T> {
+    inner: Ref<'a, T>,
+}
+
+impl<'a, T> std::ops::Deref for MappedScopeRef<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for MappedScopeRef<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Debug::fmt(&*self.inner, f)
+    }
+}
+
+/// A guard produced by [`ScopeRefMut::map`] or [`ScopeRefMut::try_map`],
+/// mutably borrowing a projection of the originally borrowed value.
+pub struct MappedScopeRefMut<'a, T> {
+    inner: RefMut<'a, T>,
+}
+
+impl<'a, T> std::ops::Deref for MappedScopeRefMut<'a, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'a, T> std::ops::DerefMut for MappedScopeRefMut<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for MappedScopeRefMut<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
@@ -0,0 +1,341 @@
+use core::fmt;
+use std::sync::PoisonError;
+#[cfg(feature = "sync")]
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError};
+
+#[cfg(not(feature = "sync"))]
+use std::cell::{Ref, RefCell, RefMut};
+
+/// Why a [`Share::try_with`]/[`Share::try_with_mut`] call failed to run the
+/// closure.
+///
+/// In the `RefCell` configuration only [`TryAccessError::WouldBlock`] is
+/// ever produced, since `RefCell` has no notion of poisoning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryAccessError {
+    /// The lock/borrow is currently held elsewhere.
+    WouldBlock,
+    /// The lock is poisoned because a prior holder panicked while it was
+    /// locked. Only possible in the `sync` configuration.
+    Poisoned,
+}
+
+impl fmt::Display for TryAccessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TryAccessError::WouldBlock => write!(f, "lock would block"),
+            TryAccessError::Poisoned => write!(f, "lock is poisoned"),
+        }
+    }
+}
+
+impl std::error::Error for TryAccessError {}
+
+// Unified shared-state wrapper whose backing store is picked by the `sync`
+// cargo feature, following the pattern rustc's `sync` module uses for its
+// `Lock`/`RwLock` aliases: `RwLock<T>` when `sync` is enabled, `RefCell<T>`
+// otherwise. The method surface and guard shapes are identical in both
+// configurations, so downstream code compiles unchanged when the feature is
+// flipped.
+/// Shared state wrapper that collapses to `RefCell` (single-threaded, no
+/// locking overhead) or `RwLock` (thread safe) depending on the `sync`
+/// cargo feature.
+pub struct Share<T> {
+    #[cfg(feature = "sync")]
+    inner: RwLock<T>,
+    #[cfg(not(feature = "sync"))]
+    inner: RefCell<T>,
+}
+
+#[cfg(feature = "sync")]
+impl<T> Share<T> {
+    /// Creates a new 'Share' wrapping the given value.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::share::Share;
+    /// let shared = Share::new(42);
+    /// ```
+    ///
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: RwLock::new(value),
+        }
+    }
+
+    /// Provides immutable access via a scoped closure.
+    ///
+    /// # Panics
+    /// Panics if the rwlock becomes poisoned.
+    #[track_caller]
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        let guard = self.inner.read().unwrap();
+        f(&guard)
+    }
+
+    /// Provides mutable access via a scoped closure.
+    ///
+    /// # Panics
+    /// Panics if the rwlock becomes poisoned.
+    #[track_caller]
+    pub fn with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let mut guard = self.inner.write().unwrap();
+        f(&mut guard)
+    }
+
+    /// Attempts to provide immutable access, distinguishing a contended
+    /// lock from a poisoned one.
+    pub fn try_with<R>(&self, f: impl FnOnce(&T) -> R) -> Result<R, TryAccessError> {
+        match self.inner.try_read() {
+            Ok(guard) => Ok(f(&guard)),
+            Err(TryLockError::WouldBlock) => Err(TryAccessError::WouldBlock),
+            Err(TryLockError::Poisoned(_)) => Err(TryAccessError::Poisoned),
+        }
+    }
+
+    /// Attempts to provide mutable access, distinguishing a contended lock
+    /// from a poisoned one.
+    pub fn try_with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> Result<R, TryAccessError> {
+        match self.inner.try_write() {
+            Ok(mut guard) => Ok(f(&mut guard)),
+            Err(TryLockError::WouldBlock) => Err(TryAccessError::WouldBlock),
+            Err(TryLockError::Poisoned(_)) => Err(TryAccessError::Poisoned),
+        }
+    }
+
+    /// Provides immutable access, surfacing poison instead of panicking.
+    ///
+    /// Mirrors `std::sync::RwLock::read`'s `LockResult`: on `Err`, the
+    /// closure still ran against the (possibly inconsistent) poisoned data.
+    pub fn with_checked<R>(&self, f: impl FnOnce(&T) -> R) -> Result<R, PoisonError<R>> {
+        match self.inner.read() {
+            Ok(guard) => Ok(f(&guard)),
+            Err(poisoned) => Err(PoisonError::new(f(&poisoned.into_inner()))),
+        }
+    }
+
+    /// Provides mutable access, surfacing poison instead of panicking.
+    ///
+    /// See [`Share::with_checked`] for the poisoned-`Err` semantics.
+    pub fn with_mut_checked<R>(&self, f: impl FnOnce(&mut T) -> R) -> Result<R, PoisonError<R>> {
+        match self.inner.write() {
+            Ok(mut guard) => Ok(f(&mut guard)),
+            Err(poisoned) => Err(PoisonError::new(f(&mut poisoned.into_inner()))),
+        }
+    }
+
+    /// Returns `true` if the lock is currently poisoned.
+    pub fn is_poisoned(&self) -> bool {
+        self.inner.is_poisoned()
+    }
+
+    /// Clears the poisoned state of the lock, letting a recovering
+    /// application keep using the value after a panic.
+    pub fn clear_poison(&self) {
+        self.inner.clear_poison();
+    }
+
+    /// Aquires an immutable borrow of the inner value.
+    ///
+    /// # Panics
+    /// Panics if the rwlock becomes poisoned.
+    #[track_caller]
+    pub fn borrow(&self) -> ShareRef<'_, T> {
+        ShareRef {
+            inner: self.inner.read().unwrap(),
+        }
+    }
+
+    /// Aquires a mutable borrow of the inner value.
+    ///
+    /// # Panics
+    /// Panics if the rwlock becomes poisoned.
+    #[track_caller]
+    pub fn borrow_mut(&self) -> ShareRefMut<'_, T> {
+        ShareRefMut {
+            inner: self.inner.write().unwrap(),
+        }
+    }
+}
+
+#[cfg(not(feature = "sync"))]
+impl<T> Share<T> {
+    /// Creates a new 'Share' wrapping the given value.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::share::Share;
+    /// let shared = Share::new(42);
+    /// ```
+    ///
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: RefCell::new(value),
+        }
+    }
+
+    /// Provides immutable access via a scoped closure.
+    ///
+    /// # Panics
+    /// Panics at runtime if a mutable borrow is already active.
+    #[track_caller]
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        let borrow = self.inner.borrow();
+        f(&borrow)
+    }
+
+    /// Provides mutable access via a scoped closure.
+    ///
+    /// # Panics
+    /// Panics at runtime if another borrow is active.
+    #[track_caller]
+    pub fn with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let mut borrow = self.inner.borrow_mut();
+        f(&mut borrow)
+    }
+
+    /// Attempts to provide immutable access, mirroring the `sync`
+    /// configuration's [`Share::try_with`] signature. `RefCell` has no
+    /// poisoning, so only [`TryAccessError::WouldBlock`] is ever returned.
+    pub fn try_with<R>(&self, f: impl FnOnce(&T) -> R) -> Result<R, TryAccessError> {
+        self.inner
+            .try_borrow()
+            .map(|borrow| f(&borrow))
+            .map_err(|_| TryAccessError::WouldBlock)
+    }
+
+    /// Attempts to provide mutable access, mirroring the `sync`
+    /// configuration's [`Share::try_with_mut`] signature. `RefCell` has no
+    /// poisoning, so only [`TryAccessError::WouldBlock`] is ever returned.
+    pub fn try_with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> Result<R, TryAccessError> {
+        self.inner
+            .try_borrow_mut()
+            .map(|mut borrow| f(&mut borrow))
+            .map_err(|_| TryAccessError::WouldBlock)
+    }
+
+    /// Provides immutable access, mirroring the `sync` configuration's
+    /// [`Share::with_checked`] signature. `RefCell` has no poisoning, so
+    /// this never returns `Err`.
+    pub fn with_checked<R>(&self, f: impl FnOnce(&T) -> R) -> Result<R, PoisonError<R>> {
+        Ok(self.with(f))
+    }
+
+    /// Provides mutable access, mirroring the `sync` configuration's
+    /// [`Share::with_mut_checked`] signature. `RefCell` has no poisoning, so
+    /// this never returns `Err`.
+    pub fn with_mut_checked<R>(&self, f: impl FnOnce(&mut T) -> R) -> Result<R, PoisonError<R>> {
+        Ok(self.with_mut(f))
+    }
+
+    /// Always `false`: `RefCell` has no notion of poisoning.
+    pub fn is_poisoned(&self) -> bool {
+        false
+    }
+
+    /// No-op: `RefCell` has no notion of poisoning.
+    pub fn clear_poison(&self) {}
+
+    /// Aquires an immutable borrow of the inner value.
+    ///
+    /// # Panics
+    /// Panics at runtime if a mutable borrow is already active.
+    #[track_caller]
+    pub fn borrow(&self) -> ShareRef<'_, T> {
+        ShareRef {
+            inner: self.inner.borrow(),
+        }
+    }
+
+    /// Aquires a mutable borrow of the inner value.
+    ///
+    /// # Panics
+    /// Panics at runtime if any other borrow is currently active.
+    #[track_caller]
+    pub fn borrow_mut(&self) -> ShareRefMut<'_, T> {
+        ShareRefMut {
+            inner: self.inner.borrow_mut(),
+        }
+    }
+}
+
+impl<T> Share<T> {
+    /// Clones and returns the inner value. Requires `T: Clone`.
+    pub fn snapshot(&self) -> T
+    where
+        T: Clone,
+    {
+        self.with(|val| val.clone())
+    }
+
+    /// Replaces the inner value with a new one.
+    pub fn replace(&self, new: T) {
+        self.with_mut(|val| *val = new);
+    }
+}
+
+// Only `RwLock<T>` makes `Share<T>` safe to share across threads; in the
+// `RefCell` configuration `Share<T>` stays single-threaded, matching the
+// auto-trait bounds of its backing store.
+#[cfg(feature = "sync")]
+unsafe impl<T: Send> Send for Share<T> {}
+#[cfg(feature = "sync")]
+unsafe impl<T: Send + Sync> Sync for Share<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for Share<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Share")
+            .field("value", &self.borrow())
+            .finish()
+    }
+}
+
+/// An immutable borrow guard returned by [`Share::borrow`].
+pub struct ShareRef<'a, T> {
+    #[cfg(feature = "sync")]
+    inner: RwLockReadGuard<'a, T>,
+    #[cfg(not(feature = "sync"))]
+    inner: Ref<'a, T>,
+}
+
+impl<'a, T> std::ops::Deref for ShareRef<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for ShareRef<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Debug::fmt(&*self.inner, f)
+    }
+}
+
+/// A mutable borrow guard returned by [`Share::borrow_mut`].
+pub struct ShareRefMut<'a, T> {
+    #[cfg(feature = "sync")]
+    inner: RwLockWriteGuard<'a, T>,
+    #[cfg(not(feature = "sync"))]
+    inner: RefMut<'a, T>,
+}
+
+impl<'a, T> std::ops::Deref for ShareRefMut<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'a, T> std::ops::DerefMut for ShareRefMut<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for ShareRefMut<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
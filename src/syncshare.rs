@@ -1,11 +1,47 @@
+use core::fmt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError};
+
+/// Why a [`SyncShare::try_with`]/[`SyncShare::try_with_mut`] call failed to
+/// run the closure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryAccessError {
+    /// The lock is currently held by another reader/writer.
+    WouldBlock,
+    /// The lock is poisoned because a prior holder panicked while it was
+    /// locked.
+    Poisoned,
+}
+
+impl fmt::Display for TryAccessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TryAccessError::WouldBlock => write!(f, "lock would block"),
+            TryAccessError::Poisoned => write!(f, "lock is poisoned"),
+        }
+    }
+}
+
+impl std::error::Error for TryAccessError {}
+
+/// Controls how [`SyncShare::with`]/[`SyncShare::with_mut`] react to a
+/// poisoned lock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum PoisonPolicy {
+    /// Panic on poison, mirroring `std::sync::RwLock`'s default behavior.
+    #[default]
+    Panic,
+    /// Treat poison as recoverable and keep using the (possibly
+    /// inconsistent) inner value instead of panicking.
+    Ignore,
+}
 
 // #[cfg(feature = "sync")]
 /// Thread safe scoped shared state wrapper with ergonomic access methods
 pub struct SyncShare<T> {
     inner: std::sync::RwLock<T>,
+    poison_policy: PoisonPolicy,
 }
 
 impl<T> SyncShare<T> {
@@ -13,75 +49,171 @@ impl<T> SyncShare<T> {
     ///
     /// # Example:
     /// ```
-    /// let shared = SyncShare::new(42)
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new(42);
     /// ```
     ///
     pub fn new(value: T) -> Self {
         Self {
             inner: RwLock::new(value),
+            poison_policy: PoisonPolicy::Panic,
+        }
+    }
+
+    /// Creates a new 'SyncShare' whose `with`/`with_mut` treat a poisoned
+    /// lock as recoverable, handing back the inner data instead of
+    /// panicking.
+    ///
+    /// # Example:
+    /// ```
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new_ignoring_poison(42);
+    /// ```
+    ///
+    pub fn new_ignoring_poison(value: T) -> Self {
+        Self {
+            inner: RwLock::new(value),
+            poison_policy: PoisonPolicy::Ignore,
         }
     }
 
     /// Provides immutable access to the inner value via a scoped closure
     ///
     /// # Panics
-    /// Panics if the rwlock becomes poisoned
+    /// Panics if the rwlock becomes poisoned, unless this `SyncShare` was
+    /// created with [`SyncShare::new_ignoring_poison`]
     ///
     /// # Example
     /// ```
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new(42);
     /// shared.with(|val| print!("{val}"));
     /// ```
     ///
     pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
-        let guard = self.inner.read().unwrap();
-        f(&*guard)
+        if self.poison_policy == PoisonPolicy::Ignore {
+            let guard = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+            f(&guard)
+        } else {
+            let guard = self.inner.read().unwrap();
+            f(&guard)
+        }
     }
 
     /// Provides immutable access to the inner value via a scoped closure
     ///
     /// #Panics
-    /// Panics if the rwlock becomes poisoned
+    /// Panics if the rwlock becomes poisoned, unless this `SyncShare` was
+    /// created with [`SyncShare::new_ignoring_poison`]
     ///
     /// # Example
     /// ```
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new(42);
     /// shared.with_mut(|val| *val += 1);
     /// ```
     ///
     pub fn with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
-        let mut guard = self.inner.write().unwrap();
-        f(&mut *guard)
+        if self.poison_policy == PoisonPolicy::Ignore {
+            let mut guard = self.inner.write().unwrap_or_else(PoisonError::into_inner);
+            f(&mut guard)
+        } else {
+            let mut guard = self.inner.write().unwrap();
+            f(&mut guard)
+        }
     }
 
-    /// Attempts to provide immutable access. Returns 'None' if the lock is unavailable
+    /// Provides immutable access to the inner value, surfacing poison
+    /// instead of panicking.
+    ///
+    /// Mirrors `std::sync::RwLock::read`'s `LockResult`: on `Err`, the
+    /// closure still ran against the (possibly inconsistent) poisoned data,
+    /// and the result is wrapped in a [`PoisonError`] so the caller can
+    /// decide whether to trust it.
     ///
     /// # Example
     /// ```
-    /// if let Some(val) = shared.try_with(|v| *v) {
-    ///     println!("value: {val}")
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new(42);
+    /// match shared.with_checked(|v| *v) {
+    ///     Ok(val) => println!("value: {val}"),
+    ///     Err(poisoned) => println!("poisoned, value was: {}", poisoned.into_inner()),
     /// }
     /// ```
     ///
-    pub fn try_with<R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
-        self.inner.try_read().ok().map(|guard| f(&*guard))
+    pub fn with_checked<R>(&self, f: impl FnOnce(&T) -> R) -> Result<R, PoisonError<R>> {
+        match self.inner.read() {
+            Ok(guard) => Ok(f(&guard)),
+            Err(poisoned) => Err(PoisonError::new(f(&poisoned.into_inner()))),
+        }
     }
 
-    /// Attempts to provide mutable access. Returns 'None' if the lock is unavailable
+    /// Provides mutable access to the inner value, surfacing poison instead
+    /// of panicking.
+    ///
+    /// See [`SyncShare::with_checked`] for the poisoned-`Err` semantics.
     ///
     /// # Example
     /// ```
-    /// if let Some(_) = shared.try_with_mut(|v| v.push(10)) {
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new(42);
+    /// let _ = shared.with_mut_checked(|v| *v += 1);
+    /// ```
+    ///
+    pub fn with_mut_checked<R>(&self, f: impl FnOnce(&mut T) -> R) -> Result<R, PoisonError<R>> {
+        match self.inner.write() {
+            Ok(mut guard) => Ok(f(&mut guard)),
+            Err(poisoned) => Err(PoisonError::new(f(&mut poisoned.into_inner()))),
+        }
+    }
+
+    /// Attempts to provide immutable access, distinguishing a contended
+    /// lock from a poisoned one.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new(42);
+    /// match shared.try_with(|v| *v) {
+    ///     Ok(val) => println!("value: {val}"),
+    ///     Err(e) => println!("no access: {e}"),
+    /// }
+    /// ```
+    ///
+    pub fn try_with<R>(&self, f: impl FnOnce(&T) -> R) -> Result<R, TryAccessError> {
+        match self.inner.try_read() {
+            Ok(guard) => Ok(f(&guard)),
+            Err(TryLockError::WouldBlock) => Err(TryAccessError::WouldBlock),
+            Err(TryLockError::Poisoned(_)) => Err(TryAccessError::Poisoned),
+        }
+    }
+
+    /// Attempts to provide mutable access, distinguishing a contended lock
+    /// from a poisoned one.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new(Vec::new());
+    /// if let Ok(_) = shared.try_with_mut(|v| v.push(10)) {
     ///     // successfully modified
     /// }
     /// ```
     ///
-    pub fn try_with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> Option<R> {
-        self.inner.try_write().ok().map(|mut guard| f(&mut *guard))
+    pub fn try_with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> Result<R, TryAccessError> {
+        match self.inner.try_write() {
+            Ok(mut guard) => Ok(f(&mut guard)),
+            Err(TryLockError::WouldBlock) => Err(TryAccessError::WouldBlock),
+            Err(TryLockError::Poisoned(_)) => Err(TryAccessError::Poisoned),
+        }
     }
 
     /// Clones and returns the inner value. Requires 'T: Clone'.
     ///
     /// # Example
     /// ```
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new(42);
     /// let snapshot = shared.snapshot();
     /// ```
     ///
@@ -96,7 +228,9 @@ impl<T> SyncShare<T> {
     ///
     /// # Example
     /// ```
-    /// shared.replace(100)
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new(42);
+    /// shared.replace(100);
     /// ```
     pub fn replace(&self, new: T) {
         self.with_mut(|val| *val = new);
@@ -109,6 +243,8 @@ impl<T> SyncShare<T> {
     ///
     /// # Example
     /// ```
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new(42);
     /// let guard = shared.borrow();
     /// println!("Value: {}", *guard);
     /// ```
@@ -124,6 +260,8 @@ impl<T> SyncShare<T> {
     ///
     /// # Example
     /// ```
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new(42);
     /// let mut guard = shared.borrow_mut();
     /// *guard += 1;
     /// ```
@@ -131,8 +269,282 @@ impl<T> SyncShare<T> {
     pub fn borrow_mut(&self) -> RwLockWriteGuard<'_, T> {
         self.inner.write().unwrap()
     }
+
+    /// Aquires an immutable borrow guard, surfacing poison instead of
+    /// panicking.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new(42);
+    /// let guard = shared.borrow_checked().unwrap_or_else(|e| e.into_inner());
+    /// ```
+    ///
+    pub fn borrow_checked(
+        &self,
+    ) -> Result<RwLockReadGuard<'_, T>, PoisonError<RwLockReadGuard<'_, T>>> {
+        self.inner.read()
+    }
+
+    /// Aquires a mutable borrow guard, surfacing poison instead of
+    /// panicking.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new(42);
+    /// let mut guard = shared.borrow_mut_checked().unwrap_or_else(|e| e.into_inner());
+    /// ```
+    ///
+    pub fn borrow_mut_checked(
+        &self,
+    ) -> Result<RwLockWriteGuard<'_, T>, PoisonError<RwLockWriteGuard<'_, T>>> {
+        self.inner.write()
+    }
+
+    /// Returns `true` if the lock is currently poisoned.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new(42);
+    /// if shared.is_poisoned() {
+    ///     shared.clear_poison();
+    /// }
+    /// ```
+    ///
+    pub fn is_poisoned(&self) -> bool {
+        self.inner.is_poisoned()
+    }
+
+    /// Clears the poisoned state of the lock, letting a recovering
+    /// application keep using the value after a panic.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::syncshare::SyncShare;
+    /// let shared = SyncShare::new(42);
+    /// shared.clear_poison();
+    /// ```
+    ///
+    pub fn clear_poison(&self) {
+        self.inner.clear_poison();
+    }
+
+    /// Converts this `SyncShare` into a [`SharedHandle`] aliasing the same
+    /// value behind an `Arc`, so cloning the handle shares one instance
+    /// across threads/tasks instead of deep-cloning it.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::syncshare::SyncShare;
+    /// let handle = SyncShare::new(42).into_handle();
+    /// let same = handle.clone();
+    /// handle.with_mut(|v| *v += 1);
+    /// assert_eq!(same.with(|v| *v), 43);
+    /// ```
+    ///
+    pub fn into_handle(self) -> SharedHandle<T> {
+        SharedHandle::new(
+            self.inner
+                .into_inner()
+                .unwrap_or_else(PoisonError::into_inner),
+        )
+    }
+}
+
+/// A read guard borrowing a projection of the value held by a
+/// [`SyncShare`], produced by [`MappedSyncRef::map`].
+///
+/// `std::sync::RwLockReadGuard` has no stable `map` combinator, so this
+/// keeps the original guard alive and stores a raw pointer to the
+/// projected field computed once at construction. The pointer stays valid
+/// for the guard's lifetime because the owning `RwLockReadGuard` is never
+/// dropped before `MappedSyncRef` is.
+pub struct MappedSyncRef<'a, T, U> {
+    _guard: RwLockReadGuard<'a, T>,
+    projected: *const U,
 }
 
+impl<'a, T, U> MappedSyncRef<'a, T, U> {
+    /// Projects a read guard onto a field (or any derived reference) of `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::syncshare::{MappedSyncRef, SyncShare};
+    /// let shared = SyncShare::new((1, 2));
+    /// let guard = shared.borrow();
+    /// let field = MappedSyncRef::map(guard, |v| &v.0);
+    /// ```
+    ///
+    pub fn map<F>(guard: RwLockReadGuard<'a, T>, f: F) -> Self
+    where
+        F: FnOnce(&T) -> &U,
+    {
+        let projected = f(&guard) as *const U;
+        Self {
+            _guard: guard,
+            projected,
+        }
+    }
+
+    /// Attempts to project a read guard onto a derived reference of `T`,
+    /// returning the original guard back if `f` fails.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::syncshare::{MappedSyncRef, SyncShare};
+    /// let shared = SyncShare::new(vec![1, 2, 3]);
+    /// let guard = shared.borrow();
+    /// let field = MappedSyncRef::try_map(guard, |v| v.get(0));
+    /// ```
+    ///
+    pub fn try_map<F>(guard: RwLockReadGuard<'a, T>, f: F) -> Result<Self, RwLockReadGuard<'a, T>>
+    where
+        F: FnOnce(&T) -> Option<&U>,
+    {
+        match f(&guard).map(|v| v as *const U) {
+            Some(projected) => Ok(Self {
+                _guard: guard,
+                projected,
+            }),
+            None => Err(guard),
+        }
+    }
+}
+
+impl<'a, T, U> std::ops::Deref for MappedSyncRef<'a, T, U> {
+    type Target = U;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: `projected` was derived from `_guard` and `_guard` is kept
+        // alive for as long as `self` is, so the pointee is always valid.
+        unsafe { &*self.projected }
+    }
+}
+
+impl<'a, T, U: fmt::Debug> fmt::Debug for MappedSyncRef<'a, T, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+// SAFETY: the `projected` pointer is only ever read through `Deref` as a
+// shared `&U`, the same access `RwLockReadGuard<T>` grants to `T`, so this
+// mirrors std's `impl<T: Sync> Sync for RwLockReadGuard<'_, T>` with `U` in
+// place of `T`. `MappedSyncRef` deliberately stays `!Send`, matching
+// `RwLockReadGuard` itself.
+unsafe impl<T, U: Sync> Sync for MappedSyncRef<'_, T, U> {}
+
+/// A write guard borrowing a mutable projection of the value held by a
+/// [`SyncShare`], produced by [`MappedSyncRefMut::map`].
+///
+/// See [`MappedSyncRef`] for why this stores a raw pointer instead of using
+/// a (nonexistent) stable `RwLockWriteGuard::map`.
+///
+/// Like `std::sync::RwLockWriteGuard`, this is `!Send`: the embedded guard
+/// must be unlocked on the thread that acquired it, so it can't be moved to
+/// another thread and dropped there.
+///
+/// ```compile_fail
+/// use scopeshare::syncshare::{MappedSyncRefMut, SyncShare};
+/// let shared = SyncShare::new(1);
+/// std::thread::scope(|scope| {
+///     let guard = shared.borrow_mut();
+///     let field = MappedSyncRefMut::map(guard, |v| v);
+///     scope.spawn(move || {
+///         let _ = *field;
+///     });
+/// });
+/// ```
+pub struct MappedSyncRefMut<'a, T, U> {
+    _guard: RwLockWriteGuard<'a, T>,
+    projected: *mut U,
+}
+
+impl<'a, T, U> MappedSyncRefMut<'a, T, U> {
+    /// Projects a write guard onto a field (or any derived mutable
+    /// reference) of `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::syncshare::{MappedSyncRefMut, SyncShare};
+    /// let shared = SyncShare::new((1, 2));
+    /// let guard = shared.borrow_mut();
+    /// let field = MappedSyncRefMut::map(guard, |v| &mut v.0);
+    /// ```
+    ///
+    pub fn map<F>(mut guard: RwLockWriteGuard<'a, T>, f: F) -> Self
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        let projected = f(&mut guard) as *mut U;
+        Self {
+            _guard: guard,
+            projected,
+        }
+    }
+
+    /// Attempts to project a write guard onto a derived mutable reference of
+    /// `T`, returning the original guard back if `f` fails.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::syncshare::{MappedSyncRefMut, SyncShare};
+    /// let shared = SyncShare::new(vec![1, 2, 3]);
+    /// let guard = shared.borrow_mut();
+    /// let field = MappedSyncRefMut::try_map(guard, |v| v.get_mut(0));
+    /// ```
+    ///
+    pub fn try_map<F>(
+        mut guard: RwLockWriteGuard<'a, T>,
+        f: F,
+    ) -> Result<Self, RwLockWriteGuard<'a, T>>
+    where
+        F: FnOnce(&mut T) -> Option<&mut U>,
+    {
+        match f(&mut guard).map(|v| v as *mut U) {
+            Some(projected) => Ok(Self {
+                _guard: guard,
+                projected,
+            }),
+            None => Err(guard),
+        }
+    }
+}
+
+impl<'a, T, U> std::ops::Deref for MappedSyncRefMut<'a, T, U> {
+    type Target = U;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: see `MappedSyncRef::deref`.
+        unsafe { &*self.projected }
+    }
+}
+
+impl<'a, T, U> std::ops::DerefMut for MappedSyncRefMut<'a, T, U> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: see `MappedSyncRef::deref`; `_guard` is a unique write
+        // guard, so no other reference to the projected data can exist.
+        unsafe { &mut *self.projected }
+    }
+}
+
+impl<'a, T, U: fmt::Debug> fmt::Debug for MappedSyncRefMut<'a, T, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+// SAFETY: `projected` is only reachable through `Deref`/`DerefMut` as `&U`/
+// `&mut U`, the same access `RwLockWriteGuard<T>` grants to `T`, so this
+// mirrors std's `impl<T: Sync> Sync for RwLockWriteGuard<'_, T>` with `U` in
+// place of `T`. `MappedSyncRefMut` deliberately stays `!Send`: it embeds a
+// live `RwLockWriteGuard`, which must be unlocked on the thread that
+// acquired it, the same reason std's `RwLockWriteGuard`/
+// `MappedRwLockWriteGuard` are unconditionally `!Send`.
+unsafe impl<T, U: Sync> Sync for MappedSyncRefMut<'_, T, U> {}
+
 #[cfg(feature = "serde")]
 impl<T: Serialize> Serialize for SyncShare<T> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -152,3 +564,133 @@ impl<'de, T: Deserialize<'de>> Deserialize<'de> for SyncShare<T> {
         T::deserialize(deserializer).map(SyncShare::new)
     }
 }
+
+static NEXT_HANDLE_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// A cheaply-cloneable handle to shared state, backed by `Arc<RwLock<T>>`.
+///
+/// `SyncShare` does not implement `Clone`; cloning a `SharedHandle`
+/// produces another owner of the *same* underlying value,
+/// analogous to the cloneable `Shared` future combinator. Produced by
+/// [`SyncShare::into_handle`] or [`SharedHandle::new`].
+pub struct SharedHandle<T> {
+    inner: std::sync::Arc<RwLock<T>>,
+    id: u64,
+}
+
+impl<T> SharedHandle<T> {
+    /// Creates a new `SharedHandle` wrapping the given value.
+    ///
+    /// # Example
+    /// ```
+    /// use scopeshare::syncshare::SharedHandle;
+    /// let handle = SharedHandle::new(42);
+    /// ```
+    ///
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: std::sync::Arc::new(RwLock::new(value)),
+            id: NEXT_HANDLE_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+        }
+    }
+
+    /// A stable id identifying the underlying value, shared by every clone
+    /// of this handle, useful for debugging.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// The number of live handles, including this one, that alias the same
+    /// underlying value.
+    pub fn strong_count(&self) -> usize {
+        std::sync::Arc::strong_count(&self.inner)
+    }
+
+    /// Provides immutable access to the inner value via a scoped closure.
+    ///
+    /// # Panics
+    /// Panics if the rwlock becomes poisoned.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        let guard = self.inner.read().unwrap();
+        f(&guard)
+    }
+
+    /// Provides mutable access to the inner value via a scoped closure.
+    ///
+    /// # Panics
+    /// Panics if the rwlock becomes poisoned.
+    pub fn with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let mut guard = self.inner.write().unwrap();
+        f(&mut guard)
+    }
+
+    /// Attempts to provide immutable access, distinguishing a contended
+    /// lock from a poisoned one.
+    pub fn try_with<R>(&self, f: impl FnOnce(&T) -> R) -> Result<R, TryAccessError> {
+        match self.inner.try_read() {
+            Ok(guard) => Ok(f(&guard)),
+            Err(TryLockError::WouldBlock) => Err(TryAccessError::WouldBlock),
+            Err(TryLockError::Poisoned(_)) => Err(TryAccessError::Poisoned),
+        }
+    }
+
+    /// Attempts to provide mutable access, distinguishing a contended lock
+    /// from a poisoned one.
+    pub fn try_with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> Result<R, TryAccessError> {
+        match self.inner.try_write() {
+            Ok(mut guard) => Ok(f(&mut guard)),
+            Err(TryLockError::WouldBlock) => Err(TryAccessError::WouldBlock),
+            Err(TryLockError::Poisoned(_)) => Err(TryAccessError::Poisoned),
+        }
+    }
+
+    /// Aquires an immutable borrow guard for the inner value.
+    ///
+    /// # Panics
+    /// Panics if the lock is poisoned.
+    pub fn borrow(&self) -> RwLockReadGuard<'_, T> {
+        self.inner.read().unwrap()
+    }
+
+    /// Aquires a mutable borrow guard for the inner value.
+    ///
+    /// # Panics
+    /// Panics if the lock is poisoned.
+    pub fn borrow_mut(&self) -> RwLockWriteGuard<'_, T> {
+        self.inner.write().unwrap()
+    }
+
+    /// Clones and returns the inner value. Requires `T: Clone`.
+    pub fn snapshot(&self) -> T
+    where
+        T: Clone,
+    {
+        self.with(|val| val.clone())
+    }
+
+    /// Replaces the inner value with a new one.
+    pub fn replace(&self, new: T) {
+        self.with_mut(|val| *val = new);
+    }
+}
+
+// Cloning a handle aliases the same underlying value (and keeps its id)
+// rather than deep-cloning the data, which is the whole point of this type.
+impl<T> Clone for SharedHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: std::sync::Arc::clone(&self.inner),
+            id: self.id,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SharedHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedHandle")
+            .field("id", &self.id)
+            .field("strong_count", &self.strong_count())
+            .field("value", &self.borrow())
+            .finish()
+    }
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "async")]
+
+use scopeshare::asyncshare::AsyncShare;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn with_and_with_mut_update_the_value() {
+    let shared = AsyncShare::new(0);
+    shared.with_mut(|v| *v += 1).await;
+    assert_eq!(shared.with(|v| *v).await, 1);
+}
+
+#[tokio::test]
+async fn try_with_fails_while_a_write_guard_is_held() {
+    let shared = AsyncShare::new(0);
+    let _guard = shared.write().await;
+    assert!(shared.try_with(|v| *v).is_none());
+}
+
+#[tokio::test]
+async fn concurrent_writers_see_a_consistent_final_value() {
+    let shared = Arc::new(AsyncShare::new(0));
+    let mut tasks = Vec::new();
+    for _ in 0..8 {
+        let shared = Arc::clone(&shared);
+        tasks.push(tokio::spawn(async move {
+            shared.with_mut(|v| *v += 1).await;
+        }));
+    }
+    for task in tasks {
+        task.await.unwrap();
+    }
+    assert_eq!(shared.with(|v| *v).await, 8);
+}
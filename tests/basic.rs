@@ -1,7 +1,105 @@
-use scopeshare::syncshare::SyncShare;
+use scopeshare::scopeshare::ScopeShare;
+use scopeshare::syncshare::{MappedSyncRef, MappedSyncRefMut, SharedHandle, SyncShare};
 
 #[test]
 fn test_basic_usage() {
     let s = SyncShare::new(42);
     assert_eq!(s.with(|x| *x), 42);
 }
+
+#[test]
+fn mapped_sync_ref_reads_projected_field() {
+    let s = SyncShare::new((1, 2));
+    let guard = s.borrow();
+    let field = MappedSyncRef::map(guard, |pair| &pair.1);
+    assert_eq!(*field, 2);
+}
+
+#[test]
+fn mapped_sync_ref_mut_writes_back() {
+    let s = SyncShare::new((1, 2));
+    {
+        let guard = s.borrow_mut();
+        let mut field = MappedSyncRefMut::map(guard, |pair| &mut pair.1);
+        *field += 10;
+    }
+    assert_eq!(s.with(|pair| *pair), (1, 12));
+}
+
+#[test]
+fn mapped_sync_ref_is_sync_across_threads() {
+    let s = SyncShare::new((1, 2));
+    let guard = s.borrow();
+    let field = MappedSyncRef::map(guard, |pair| &pair.1);
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| assert_eq!(*field, 2));
+        }
+    });
+}
+
+#[test]
+fn mapped_sync_ref_try_map_returns_original_on_failure() {
+    let s = SyncShare::new(vec![1, 2, 3]);
+    let guard = s.borrow();
+    let result = MappedSyncRef::try_map(guard, |v| v.get(10));
+    match result {
+        Ok(_) => panic!("expected the failing projection to hand back the original guard"),
+        Err(guard) => assert_eq!(*guard, vec![1, 2, 3]),
+    }
+}
+
+#[test]
+fn mapped_sync_ref_mut_try_map_writes_back() {
+    let s = SyncShare::new(vec![1, 2, 3]);
+    {
+        let guard = s.borrow_mut();
+        let mut field = MappedSyncRefMut::try_map(guard, |v| v.get_mut(0))
+            .unwrap_or_else(|_| panic!("expected the projection to succeed"));
+        *field += 10;
+    }
+    assert_eq!(s.with(|v| v[0]), 11);
+}
+
+#[test]
+fn shared_handle_clones_alias_the_same_value() {
+    let handle = SharedHandle::new(1);
+    let same = handle.clone();
+    handle.with_mut(|v| *v += 10);
+    assert_eq!(same.with(|v| *v), 11);
+}
+
+#[test]
+fn shared_handle_id_is_stable_across_clones() {
+    let handle = SharedHandle::new(1);
+    let same = handle.clone();
+    assert_eq!(handle.id(), same.id());
+}
+
+#[test]
+fn shared_handle_strong_count_tracks_live_clones() {
+    let handle = SharedHandle::new(1);
+    assert_eq!(handle.strong_count(), 1);
+    let same = handle.clone();
+    assert_eq!(handle.strong_count(), 2);
+    drop(same);
+    assert_eq!(handle.strong_count(), 1);
+}
+
+#[test]
+fn into_handle_preserves_the_value_and_starts_with_one_owner() {
+    let handle = SyncShare::new(42).into_handle();
+    assert_eq!(handle.with(|v| *v), 42);
+    assert_eq!(handle.strong_count(), 1);
+}
+
+#[test]
+fn scope_ref_try_map_returns_original_on_failure() {
+    let s = ScopeShare::new(vec![1, 2, 3]);
+    let guard = s.borrow();
+    let result = scopeshare::scopeshare::ScopeRef::try_map(guard, |v| v.get(10));
+    match result {
+        Ok(_) => panic!("expected the failing projection to hand back the original guard"),
+        Err(guard) => assert_eq!(*guard, vec![1, 2, 3]),
+    }
+}
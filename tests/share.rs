@@ -0,0 +1,77 @@
+use scopeshare::share::Share;
+
+#[test]
+fn with_and_with_mut_update_the_value() {
+    let shared = Share::new(1);
+    shared.with_mut(|v| *v += 1);
+    assert_eq!(shared.with(|v| *v), 2);
+}
+
+#[test]
+fn try_with_succeeds_when_uncontended() {
+    let shared = Share::new(10);
+    assert_eq!(shared.try_with(|v| *v).unwrap(), 10);
+}
+
+#[test]
+fn try_with_mut_fails_while_a_mutable_borrow_is_held() {
+    let shared = Share::new(0);
+    let _guard = shared.borrow_mut();
+    assert!(shared.try_with_mut(|v| *v += 1).is_err());
+}
+
+#[test]
+fn borrow_and_borrow_mut_expose_the_value() {
+    let shared = Share::new((1, 2));
+    assert_eq!(shared.borrow().0, 1);
+    shared.borrow_mut().1 = 20;
+    assert_eq!(shared.with(|pair| *pair), (1, 20));
+}
+
+#[test]
+fn snapshot_clones_and_replace_overwrites() {
+    let shared = Share::new(vec![1, 2, 3]);
+    let snapshot = shared.snapshot();
+    assert_eq!(snapshot, vec![1, 2, 3]);
+    shared.replace(vec![9]);
+    assert_eq!(shared.with(|v| v.clone()), vec![9]);
+}
+
+#[test]
+fn with_checked_and_is_poisoned_report_no_poison_on_a_fresh_share() {
+    let shared = Share::new(42);
+    assert!(!shared.is_poisoned());
+    assert_eq!(shared.with_checked(|v| *v).unwrap(), 42);
+    assert_eq!(
+        shared
+            .with_mut_checked(|v| {
+                *v += 1;
+                *v
+            })
+            .unwrap(),
+        43
+    );
+    shared.clear_poison();
+    assert!(!shared.is_poisoned());
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn sync_configuration_detects_and_clears_poison() {
+    use scopeshare::share::TryAccessError;
+    use std::panic;
+
+    let shared = Share::new(0);
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        shared.with_mut(|v| {
+            *v += 1;
+            panic!("poison the lock");
+        });
+    }));
+    assert!(result.is_err());
+    assert!(shared.is_poisoned());
+    assert_eq!(shared.try_with(|v| *v), Err(TryAccessError::Poisoned));
+    assert_eq!(shared.with_checked(|v| *v).unwrap_err().into_inner(), 1);
+    shared.clear_poison();
+    assert!(!shared.is_poisoned());
+}